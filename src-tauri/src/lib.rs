@@ -4,7 +4,9 @@ use python_backend::PythonBackend;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    tauri::Builder::default()
+    let builder = python_backend::register_backend_proxy(tauri::Builder::default());
+
+    builder
         .plugin(tauri_plugin_opener::init())
         .manage(PythonBackend::new())
         .invoke_handler(tauri::generate_handler![
@@ -13,6 +15,7 @@ pub fn run() {
             python_backend::is_backend_running,
             python_backend::check_backend_health,
             python_backend::get_backend_url,
+            python_backend::get_backend_port,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
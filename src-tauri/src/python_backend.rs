@@ -1,31 +1,291 @@
-use std::process::{Child, Command, Stdio};
-use std::sync::Mutex;
-use tauri::State;
+use std::collections::VecDeque;
+use std::env;
+use std::path::Path;
+use std::process::Stdio;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, Runtime, State};
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::{Child, Command};
+use tokio::time::Instant;
+
+/// Custom URI scheme the webview talks to instead of the raw backend host,
+/// so the local port is never exposed to the page and CORS/CSP stay simple.
+const BACKEND_PROXY_SCHEME: &str = "langconfig-backend";
+
+const BACKEND_HOST: &str = "127.0.0.1";
+
+fn backend_url(port: u16) -> String {
+    format!("http://{BACKEND_HOST}:{port}")
+}
+
+/// Shared `reqwest` client, reused across health polls, readiness checks,
+/// and proxied requests so they benefit from connection keep-alive instead
+/// of each paying fresh-connection-pool setup cost.
+fn http_client() -> &'static reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    CLIENT.get_or_init(reqwest::Client::new)
+}
+
+/// Bind to an OS-assigned port to find one that's free, then release it for
+/// the Python process to bind. Narrow but unavoidable race between the two
+/// binds; a second `start_python_backend` call would simply retry.
+fn allocate_port() -> Result<u16, String> {
+    std::net::TcpListener::bind((BACKEND_HOST, 0))
+        .and_then(|listener| listener.local_addr())
+        .map(|addr| addr.port())
+        .map_err(|e| format!("Failed to allocate a backend port: {}", e))
+}
+
+/// How long to wait for the backend to exit on its own after a graceful
+/// shutdown request before falling back to a hard kill.
+const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(5);
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How often the supervisor checks on the backend between restarts.
+const HEALTH_POLL_INTERVAL: Duration = Duration::from_secs(3);
+/// Consecutive failed health checks before the supervisor treats the
+/// backend as unhealthy and restarts it.
+const MAX_CONSECUTIVE_HEALTH_FAILURES: u32 = 3;
+const INITIAL_RESTART_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_RESTART_BACKOFF: Duration = Duration::from_secs(30);
+/// How long the backend must stay healthy before the backoff resets to
+/// `INITIAL_RESTART_BACKOFF`.
+const HEALTHY_RESET_WINDOW: Duration = Duration::from_secs(60);
+
+/// How long `start_python_backend` waits for `/health` to come up before
+/// giving up on the freshly spawned process.
+const READINESS_TIMEOUT: Duration = Duration::from_secs(30);
+const READINESS_POLL_INTERVAL: Duration = Duration::from_millis(200);
+/// Number of trailing stderr lines kept in memory to surface in error
+/// messages if the backend crashes before it becomes ready.
+const STDERR_TAIL_LINES: usize = 20;
+
+/// Lifecycle states broadcast to the frontend via `backend-state-changed`.
+#[derive(Clone, Copy, Serialize)]
+enum BackendState {
+    Starting,
+    Healthy,
+    Unhealthy,
+    Crashed,
+    Restarting,
+}
+
+fn emit_state(app: &AppHandle, state: BackendState) {
+    let _ = app.emit("backend-state-changed", state);
+}
+
+/// Outcome of `stop_python_backend`, so the UI can tell a clean exit from a
+/// forced one.
+#[derive(Clone, Copy, Serialize)]
+pub struct ShutdownOutcome {
+    pub graceful: bool,
+}
+
+/// One line of output captured from the Python backend process, forwarded
+/// to the webview as a `python-backend-log` event.
+#[derive(Clone, Serialize)]
+struct LogLine {
+    stream: &'static str,
+    line: String,
+}
+
+/// Names to probe for on `PATH`, in preference order.
+const PYTHON_CANDIDATES: &[&str] = &["python", "python3", "python2"];
+
+/// Resolve which Python interpreter to launch the backend with.
+///
+/// Honors a `LANGCONFIG_PYTHON` override (useful for pinning a specific
+/// interpreter or virtualenv) before falling back to scanning `PATH` for
+/// `python`, then `python3`, then `python2`.
+fn resolve_python() -> Result<String, String> {
+    if let Ok(override_path) = env::var("LANGCONFIG_PYTHON") {
+        return Ok(override_path);
+    }
+
+    let path_var = env::var_os("PATH").ok_or_else(|| "PATH is not set".to_string())?;
+    let dirs: Vec<_> = env::split_paths(&path_var).collect();
+
+    for candidate in PYTHON_CANDIDATES {
+        for dir in &dirs {
+            let exe = if env::consts::EXE_EXTENSION.is_empty() {
+                candidate.to_string()
+            } else {
+                format!("{candidate}.{}", env::consts::EXE_EXTENSION)
+            };
+            let full_path = dir.join(&exe);
+            if is_executable(&full_path) {
+                return Ok(full_path.to_string_lossy().into_owned());
+            }
+        }
+    }
+
+    Err(
+        "Could not find a Python interpreter (tried python, python3, python2 on PATH); \
+         set LANGCONFIG_PYTHON to override"
+            .to_string(),
+    )
+}
+
+fn is_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        path.metadata()
+            .map(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+            .unwrap_or(false)
+    }
+    #[cfg(not(unix))]
+    {
+        path.is_file()
+    }
+}
 
 /// Global state to hold the Python backend process
 pub struct PythonBackend {
     pub process: Mutex<Option<Child>>,
+    /// Port the currently running backend was started on, chosen freshly
+    /// for each spawn to avoid clashing with whatever else holds 8765.
+    port: Mutex<Option<u16>>,
+    /// Disabled by `stop_python_backend` before killing the process, so the
+    /// supervisor doesn't immediately resurrect a deliberate shutdown.
+    should_supervise: AtomicBool,
+    /// Set for the duration of `start_python_backend`'s spawn-and-wait
+    /// sequence so a second concurrent call is rejected outright instead of
+    /// racing it to spawn a duplicate backend.
+    starting: AtomicBool,
+    /// Handle to the running supervisor loop, if any, so `stop_python_backend`
+    /// can cancel it outright rather than hoping it notices `should_supervise`
+    /// in time.
+    supervisor: Mutex<Option<tauri::async_runtime::JoinHandle<()>>>,
 }
 
 impl PythonBackend {
     pub fn new() -> Self {
         Self {
             process: Mutex::new(None),
+            port: Mutex::new(None),
+            should_supervise: AtomicBool::new(false),
+            starting: AtomicBool::new(false),
+            supervisor: Mutex::new(None),
         }
     }
 }
 
+/// Clears `PythonBackend::starting` on drop so every return path out of
+/// `start_python_backend` — success, early error, or `?` — releases the
+/// in-flight-start guard.
+struct StartGuard<'a>(&'a AtomicBool);
+
+impl Drop for StartGuard<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
+
 #[tauri::command]
-pub async fn start_python_backend(state: State<'_, PythonBackend>) -> Result<String, String> {
-    let mut process_guard = state.process.lock().map_err(|e| e.to_string())?;
+pub async fn start_python_backend(
+    app: AppHandle,
+    state: State<'_, PythonBackend>,
+) -> Result<String, String> {
+    if state
+        .starting
+        .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+        .is_err()
+    {
+        return Err("Python backend is already starting".to_string());
+    }
+    let _start_guard = StartGuard(&state.starting);
 
-    // Check if already running
-    if let Some(child) = process_guard.as_mut() {
-        if let Ok(None) = child.try_wait() {
-            return Err("Python backend is already running".to_string());
+    {
+        // Check if already running. Scoped so the std Mutex guard is
+        // dropped before any `.await` below — holding it across a suspend
+        // point would make this command's future `!Send`, which
+        // `tauri::async_runtime::spawn` requires.
+        let mut process_guard = state.process.lock().map_err(|e| e.to_string())?;
+        if let Some(child) = process_guard.as_mut() {
+            if let Ok(None) = child.try_wait() {
+                return Err("Python backend is already running".to_string());
+            }
         }
     }
 
+    let (mut child, stderr_tail, port) = spawn_backend_child(&app).await?;
+
+    if let Err(e) = wait_until_ready(&mut child, &stderr_tail, port).await {
+        let _ = child.kill().await;
+        let _ = child.wait().await;
+        return Err(e);
+    }
+
+    {
+        let mut process_guard = state.process.lock().map_err(|e| e.to_string())?;
+        *process_guard = Some(child);
+    }
+    *state.port.lock().map_err(|e| e.to_string())? = Some(port);
+
+    state.should_supervise.store(true, Ordering::SeqCst);
+    let handle = spawn_supervisor(app);
+    *state.supervisor.lock().map_err(|e| e.to_string())? = Some(handle);
+
+    Ok("Python backend started successfully".to_string())
+}
+
+/// Poll `/health` until it responds successfully or `READINESS_TIMEOUT`
+/// elapses, also watching for an early crash so a broken launch fails fast
+/// with the captured stderr tail instead of a generic timeout.
+async fn wait_until_ready(
+    child: &mut Child,
+    stderr_tail: &Arc<Mutex<VecDeque<String>>>,
+    port: u16,
+) -> Result<(), String> {
+    let deadline = tokio::time::Instant::now() + READINESS_TIMEOUT;
+
+    while tokio::time::Instant::now() < deadline {
+        if let Ok(Some(status)) = child.try_wait() {
+            return Err(format!(
+                "Python backend exited early with {status}; stderr:\n{}",
+                tail_snapshot(stderr_tail)
+            ));
+        }
+
+        if poll_health_once(port).await {
+            return Ok(());
+        }
+
+        tokio::time::sleep(READINESS_POLL_INTERVAL).await;
+    }
+
+    Err(format!(
+        "Timed out after {}s waiting for the Python backend to become ready; stderr:\n{}",
+        READINESS_TIMEOUT.as_secs(),
+        tail_snapshot(stderr_tail)
+    ))
+}
+
+fn tail_snapshot(tail: &Arc<Mutex<VecDeque<String>>>) -> String {
+    tail.lock()
+        .map(|lines| {
+            lines
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .unwrap_or_default()
+}
+
+/// Resolve the interpreter and spawn the backend process, attaching log
+/// forwarders to its stdout/stderr. Shared by `start_python_backend` and the
+/// supervisor's restart path. Returns the child, a rolling tail of its
+/// stderr (used to enrich readiness and crash error messages), and the port
+/// it was told to listen on.
+async fn spawn_backend_child(
+    app: &AppHandle,
+) -> Result<(Child, Arc<Mutex<VecDeque<String>>>, u16), String> {
     // NOTE: Requires Python 3.10+ installed on the system.
     // This is acceptable for opensource repo where developers have Python.
     // For production app store distributions, see docs/future-enhancements/python-bundling-guide.md
@@ -35,36 +295,277 @@ pub async fn start_python_backend(state: State<'_, PythonBackend>) -> Result<Str
         .map_err(|e| e.to_string())?
         .join("backend");
 
-    // Start Python backend using system Python
-    let child = Command::new("python")
+    let port = allocate_port()?;
+
+    // Start Python backend using a resolved system Python interpreter
+    let python = resolve_python()?;
+    let mut child = Command::new(python)
         .arg("main.py")
+        .arg("--port")
+        .arg(port.to_string())
+        .env("LANGCONFIG_PORT", port.to_string())
         .current_dir(&backend_dir)
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
+        // So an aborted supervisor task (see `stop_python_backend`) can't
+        // leak this process — dropping the `Child` kills it instead of
+        // leaving it running unsupervised.
+        .kill_on_drop(true)
         .spawn()
         .map_err(|e| format!("Failed to start Python backend: {}", e))?;
 
-    *process_guard = Some(child);
+    let stderr_tail = Arc::new(Mutex::new(VecDeque::with_capacity(STDERR_TAIL_LINES)));
 
-    Ok("Python backend started successfully".to_string())
+    let stdout = child.stdout.take().expect("stdout was piped");
+    let stderr = child.stderr.take().expect("stderr was piped");
+    spawn_log_forwarder(app.clone(), stdout, "stdout", None);
+    spawn_log_forwarder(app.clone(), stderr, "stderr", Some(stderr_tail.clone()));
+
+    Ok((child, stderr_tail, port))
+}
+
+/// Watches the backend process and its `/health` endpoint, restarting it
+/// with exponential backoff if it crashes or stops responding. Returns the
+/// task handle so `stop_python_backend` can abort it outright — relying on
+/// `should_supervise` alone leaves a window mid-restart where a stop can be
+/// missed.
+fn spawn_supervisor(app: AppHandle) -> tauri::async_runtime::JoinHandle<()> {
+    tauri::async_runtime::spawn(async move {
+        let mut backoff = INITIAL_RESTART_BACKOFF;
+        let mut consecutive_failures = 0u32;
+        let mut healthy_since: Option<Instant> = None;
+
+        loop {
+            tokio::time::sleep(HEALTH_POLL_INTERVAL).await;
+
+            let state = app.state::<PythonBackend>();
+            if !state.should_supervise.load(Ordering::SeqCst) {
+                break;
+            }
+
+            let crashed = match state.process.lock() {
+                Ok(mut guard) => match guard.as_mut() {
+                    Some(child) => matches!(child.try_wait(), Ok(Some(_))),
+                    None => true,
+                },
+                Err(_) => break,
+            };
+
+            if crashed {
+                emit_state(&app, BackendState::Crashed);
+                consecutive_failures = 0;
+                healthy_since = None;
+                backoff = restart_with_backoff(&app, backoff).await;
+                continue;
+            }
+
+            let port = match state.port.lock() {
+                Ok(guard) => *guard,
+                Err(_) => break,
+            };
+            let Some(port) = port else {
+                continue;
+            };
+
+            if poll_health_once(port).await {
+                consecutive_failures = 0;
+                emit_state(&app, BackendState::Healthy);
+                match healthy_since {
+                    Some(since) if since.elapsed() >= HEALTHY_RESET_WINDOW => {
+                        backoff = INITIAL_RESTART_BACKOFF;
+                    }
+                    None => healthy_since = Some(Instant::now()),
+                    _ => {}
+                }
+            } else {
+                healthy_since = None;
+                consecutive_failures += 1;
+                if consecutive_failures >= MAX_CONSECUTIVE_HEALTH_FAILURES {
+                    emit_state(&app, BackendState::Unhealthy);
+                    backoff = restart_with_backoff(&app, backoff).await;
+                    consecutive_failures = 0;
+                }
+            }
+        }
+    })
+}
+
+/// Wait out `backoff`, then spawn a fresh backend process, give it the same
+/// readiness grace period as a first-time start (so a backend that's merely
+/// slow to boot doesn't get immediately restart-thrashed by the supervisor's
+/// much shorter health-failure window), and store it if it comes up.
+/// Returns the next (doubled, capped) backoff to use if it dies again, or
+/// `backoff` unchanged if supervision was turned off in the meantime.
+async fn restart_with_backoff(app: &AppHandle, backoff: Duration) -> Duration {
+    emit_state(app, BackendState::Restarting);
+    tokio::time::sleep(backoff).await;
+
+    let state = app.state::<PythonBackend>();
+    match spawn_backend_child(app).await {
+        Ok((mut child, stderr_tail, port)) => {
+            emit_state(app, BackendState::Starting);
+
+            if wait_until_ready(&mut child, &stderr_tail, port).await.is_err() {
+                let _ = child.kill().await;
+                let _ = child.wait().await;
+                emit_state(app, BackendState::Crashed);
+                return Duration::min(backoff * 2, MAX_RESTART_BACKOFF);
+            }
+
+            if !state.should_supervise.load(Ordering::SeqCst) {
+                // `stop_python_backend` ran while we were backing off or
+                // waiting for readiness; discard the child we just brought up
+                // instead of resurrecting a backend the user asked to stop.
+                let _ = child.kill().await;
+                let _ = child.wait().await;
+                return backoff;
+            }
+
+            if let Ok(mut guard) = state.process.lock() {
+                *guard = Some(child);
+            }
+            if let Ok(mut guard) = state.port.lock() {
+                *guard = Some(port);
+            }
+            emit_state(app, BackendState::Healthy);
+        }
+        Err(_) => {
+            emit_state(app, BackendState::Crashed);
+        }
+    }
+
+    Duration::min(backoff * 2, MAX_RESTART_BACKOFF)
+}
+
+async fn poll_health_once(port: u16) -> bool {
+    http_client()
+        .get(format!("{}/health", backend_url(port)))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await
+        .map(|response| response.status().is_success())
+        .unwrap_or(false)
+}
+
+/// Read lines from a child pipe and forward them to the webview as
+/// `python-backend-log` events so crashes and progress are observable
+/// instead of silently filling an unread pipe buffer. When `tail` is given,
+/// each line is also kept in a rolling buffer for later crash reporting.
+fn spawn_log_forwarder(
+    app: AppHandle,
+    pipe: impl tokio::io::AsyncRead + Unpin + Send + 'static,
+    stream: &'static str,
+    tail: Option<Arc<Mutex<VecDeque<String>>>>,
+) {
+    tauri::async_runtime::spawn(async move {
+        let mut lines = BufReader::new(pipe).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            if let Some(tail) = &tail {
+                if let Ok(mut tail) = tail.lock() {
+                    if tail.len() == STDERR_TAIL_LINES {
+                        tail.pop_front();
+                    }
+                    tail.push_back(line.clone());
+                }
+            }
+            let _ = app.emit("python-backend-log", LogLine { stream, line });
+        }
+    });
 }
 
 #[tauri::command]
-pub async fn stop_python_backend(state: State<'_, PythonBackend>) -> Result<String, String> {
-    let mut process_guard = state.process.lock().map_err(|e| e.to_string())?;
+pub async fn stop_python_backend(
+    state: State<'_, PythonBackend>,
+) -> Result<ShutdownOutcome, String> {
+    // Disable supervision and cancel the supervisor task outright — aborting
+    // it (rather than relying on it to notice `should_supervise`) closes the
+    // window where a stop arrives mid-restart, after the new child has
+    // passed its readiness check but before the supervisor loop next checks
+    // the flag.
+    state.should_supervise.store(false, Ordering::SeqCst);
+    if let Some(handle) = state.supervisor.lock().map_err(|e| e.to_string())?.take() {
+        handle.abort();
+    }
 
-    if let Some(mut child) = process_guard.take() {
+    // Take the child out of its guard and drop the guard immediately — it's
+    // a std Mutex guard, so holding it across the `.await`s below would make
+    // this command's future `!Send`.
+    let mut child = {
+        let mut process_guard = state.process.lock().map_err(|e| e.to_string())?;
+        match process_guard.take() {
+            Some(child) => child,
+            None => return Err("Python backend is not running".to_string()),
+        }
+    };
+
+    let graceful = request_graceful_shutdown(&mut child, SHUTDOWN_GRACE_PERIOD).await?;
+
+    if !graceful {
         child
             .kill()
+            .await
             .map_err(|e| format!("Failed to kill Python process: {}", e))?;
-        child
-            .wait()
-            .map_err(|e| format!("Failed to wait for Python process: {}", e))?;
+    }
+    child
+        .wait()
+        .await
+        .map_err(|e| format!("Failed to wait for Python process: {}", e))?;
+    *state.port.lock().map_err(|e| e.to_string())? = None;
 
-        Ok("Python backend stopped successfully".to_string())
-    } else {
-        Err("Python backend is not running".to_string())
+    Ok(ShutdownOutcome { graceful })
+}
+
+/// Ask the child to exit on its own (`SIGTERM` on Unix, a non-forceful
+/// `taskkill` on Windows), then poll for up to `grace_period` before giving
+/// up. Returns `true` if the process exited gracefully within that window.
+async fn request_graceful_shutdown(
+    child: &mut Child,
+    grace_period: Duration,
+) -> Result<bool, String> {
+    let Some(pid) = child.id() else {
+        // Already reaped; nothing to signal.
+        return Ok(true);
+    };
+
+    send_terminate_signal(pid).await?;
+
+    let deadline = tokio::time::Instant::now() + grace_period;
+    while tokio::time::Instant::now() < deadline {
+        match child.try_wait() {
+            Ok(Some(_)) => return Ok(true),
+            Ok(None) => tokio::time::sleep(SHUTDOWN_POLL_INTERVAL).await,
+            Err(e) => return Err(format!("Error polling Python process: {}", e)),
+        }
+    }
+
+    Ok(false)
+}
+
+#[cfg(unix)]
+async fn send_terminate_signal(pid: u32) -> Result<(), String> {
+    // SAFETY: `kill` with a valid pid and SIGTERM is always safe to call;
+    // it's just a signal delivery, not a memory operation.
+    let result = unsafe { libc::kill(pid as libc::pid_t, libc::SIGTERM) };
+    if result != 0 {
+        return Err(format!(
+            "Failed to send SIGTERM to Python process: {}",
+            std::io::Error::last_os_error()
+        ));
     }
+    Ok(())
+}
+
+#[cfg(windows)]
+async fn send_terminate_signal(pid: u32) -> Result<(), String> {
+    // No `/F`, so this asks the process to close rather than killing it
+    // outright. Runs via `tokio::process::Command` rather than
+    // `std::process::Command` so it doesn't block the async worker thread.
+    Command::new("taskkill")
+        .args(["/PID", &pid.to_string()])
+        .status()
+        .await
+        .map_err(|e| format!("Failed to run taskkill: {}", e))?;
+    Ok(())
 }
 
 #[tauri::command]
@@ -86,13 +587,13 @@ pub async fn is_backend_running(state: State<'_, PythonBackend>) -> Result<bool,
 }
 
 #[tauri::command]
-pub async fn check_backend_health() -> Result<String, String> {
-    // Make HTTP request to backend health endpoint
-    let client = reqwest::Client::new();
+pub async fn check_backend_health(state: State<'_, PythonBackend>) -> Result<String, String> {
+    let port = get_backend_port(state)?;
 
-    match client
-        .get("http://127.0.0.1:8765/health")
-        .timeout(std::time::Duration::from_secs(5))
+    // Make HTTP request to backend health endpoint
+    match http_client()
+        .get(format!("{}/health", backend_url(port)))
+        .timeout(Duration::from_secs(5))
         .send()
         .await
     {
@@ -108,6 +609,211 @@ pub async fn check_backend_health() -> Result<String, String> {
 }
 
 #[tauri::command]
-pub fn get_backend_url() -> String {
-    "http://127.0.0.1:8765".to_string()
+pub fn get_backend_url(state: State<'_, PythonBackend>) -> Result<String, String> {
+    get_backend_port(state).map(backend_url)
+}
+
+#[tauri::command]
+pub fn get_backend_port(state: State<'_, PythonBackend>) -> Result<u16, String> {
+    state
+        .port
+        .lock()
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Python backend is not running".to_string())
+}
+
+/// Register the `langconfig-backend://` scheme so the webview can talk to
+/// the Python backend without ever learning its real host/port. Requests
+/// are reconstructed against the backend's base URL and forwarded with
+/// `reqwest`; the response's status, headers and body are passed back once
+/// the body has been read in full.
+pub fn register_backend_proxy<R: Runtime>(builder: tauri::Builder<R>) -> tauri::Builder<R> {
+    builder.register_asynchronous_uri_scheme_protocol(
+        BACKEND_PROXY_SCHEME,
+        |ctx, request, responder| {
+            let app = ctx.app_handle().clone();
+            tauri::async_runtime::spawn(async move {
+                responder.respond(forward_to_backend(&app, request).await);
+            });
+        },
+    )
+}
+
+async fn forward_to_backend<R: Runtime>(
+    app: &AppHandle<R>,
+    request: tauri::http::Request<Vec<u8>>,
+) -> tauri::http::Response<Vec<u8>> {
+    let port = match get_backend_port(app.state::<PythonBackend>()) {
+        Ok(port) => port,
+        Err(e) => return bad_gateway_response(&e),
+    };
+
+    let path_and_query = request
+        .uri()
+        .path_and_query()
+        .map(|p| p.as_str())
+        .unwrap_or("/");
+    let url = format!("{}{}", backend_url(port), path_and_query);
+
+    let method = match reqwest::Method::from_bytes(request.method().as_str().as_bytes()) {
+        Ok(method) => method,
+        Err(e) => return bad_gateway_response(&e.to_string()),
+    };
+
+    let mut forwarded = http_client()
+        .request(method, url)
+        .body(request.body().clone());
+    for (name, value) in request.headers() {
+        forwarded = forwarded.header(name, value);
+    }
+
+    let response = match forwarded.send().await {
+        Ok(response) => response,
+        Err(e) => return bad_gateway_response(&format!("Failed to reach backend: {}", e)),
+    };
+
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body = response.bytes().await.unwrap_or_default().to_vec();
+
+    let mut builder = tauri::http::Response::builder().status(status.as_u16());
+    for (name, value) in headers.iter() {
+        builder = builder.header(name, value);
+    }
+    builder
+        .body(body)
+        .unwrap_or_else(|_| bad_gateway_response("Failed to build proxied response"))
+}
+
+fn bad_gateway_response(message: &str) -> tauri::http::Response<Vec<u8>> {
+    tauri::http::Response::builder()
+        .status(502)
+        .body(message.as_bytes().to_vec())
+        .unwrap_or_else(|_| tauri::http::Response::new(Vec::new()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::sync::Mutex as StdMutex;
+
+    /// `resolve_python` reads process-wide env vars, so tests that touch
+    /// `PATH`/`LANGCONFIG_PYTHON` must not run concurrently with each other.
+    static ENV_LOCK: StdMutex<()> = StdMutex::new(());
+
+    fn scratch_dir(label: &str) -> std::path::PathBuf {
+        let dir = env::temp_dir().join(format!("langconfig-test-{label}-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn touch_executable(dir: &Path, name: &str) {
+        let exe_name = if env::consts::EXE_EXTENSION.is_empty() {
+            name.to_string()
+        } else {
+            format!("{name}.{}", env::consts::EXE_EXTENSION)
+        };
+        let path = dir.join(exe_name);
+        fs::write(&path, "#!/bin/sh\n").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        }
+    }
+
+    #[test]
+    fn langconfig_python_override_wins() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        env::set_var("LANGCONFIG_PYTHON", "/custom/python3.11");
+        let resolved = resolve_python();
+        env::remove_var("LANGCONFIG_PYTHON");
+
+        assert_eq!(resolved, Ok("/custom/python3.11".to_string()));
+    }
+
+    #[test]
+    fn prefers_python_over_python3_on_path() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        env::remove_var("LANGCONFIG_PYTHON");
+        let dir = scratch_dir("prefers-python");
+        touch_executable(&dir, "python");
+        touch_executable(&dir, "python3");
+
+        let old_path = env::var_os("PATH");
+        env::set_var("PATH", &dir);
+        let resolved = resolve_python();
+        if let Some(path) = old_path {
+            env::set_var("PATH", path);
+        }
+        fs::remove_dir_all(&dir).ok();
+
+        let expected = if env::consts::EXE_EXTENSION.is_empty() {
+            dir.join("python")
+        } else {
+            dir.join(format!("python.{}", env::consts::EXE_EXTENSION))
+        };
+        assert_eq!(resolved, Ok(expected.to_string_lossy().into_owned()));
+    }
+
+    #[test]
+    fn falls_back_to_python3_when_python_missing() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        env::remove_var("LANGCONFIG_PYTHON");
+        let dir = scratch_dir("falls-back-python3");
+        touch_executable(&dir, "python3");
+
+        let old_path = env::var_os("PATH");
+        env::set_var("PATH", &dir);
+        let resolved = resolve_python();
+        if let Some(path) = old_path {
+            env::set_var("PATH", path);
+        }
+        fs::remove_dir_all(&dir).ok();
+
+        let expected = if env::consts::EXE_EXTENSION.is_empty() {
+            dir.join("python3")
+        } else {
+            dir.join(format!("python3.{}", env::consts::EXE_EXTENSION))
+        };
+        assert_eq!(resolved, Ok(expected.to_string_lossy().into_owned()));
+    }
+
+    #[test]
+    fn errors_when_no_interpreter_on_path() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        env::remove_var("LANGCONFIG_PYTHON");
+        let dir = scratch_dir("no-interpreter");
+
+        let old_path = env::var_os("PATH");
+        env::set_var("PATH", &dir);
+        let resolved = resolve_python();
+        if let Some(path) = old_path {
+            env::set_var("PATH", path);
+        }
+        fs::remove_dir_all(&dir).ok();
+
+        assert!(resolved.is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn is_executable_rejects_non_executable_file() {
+        let dir = scratch_dir("non-executable");
+        let path = dir.join("python");
+        fs::write(&path, "not executable").unwrap();
+
+        assert!(!is_executable(&path));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn is_executable_rejects_missing_file() {
+        let dir = scratch_dir("missing-file");
+        assert!(!is_executable(&dir.join("does-not-exist")));
+        fs::remove_dir_all(&dir).ok();
+    }
 }